@@ -1,4 +1,7 @@
-pub trait Weak: Clone {
+// Named `Pointer`, not `Weak`: strong pointers (`Rc`, `Arc`) implement this trait too, so a name
+// describing the weak case only would be misleading now that `rc::WeakKey` needs it alongside
+// `RcKey`.
+pub trait Pointer: Clone {
     type Strong;
     type Key: PartialEq + Eq + PartialOrd + Ord + core::hash::Hash + core::fmt::Debug;
 
@@ -6,78 +9,78 @@ pub trait Weak: Clone {
     fn key(&self) -> Self::Key;
 }
 
-// A thin wrapper around `W` suitable for use as a key.
+// A thin wrapper around `P` suitable for use as a key.
 //
 // Equality and comparisons are implemented in terms of the inner value pointer and the hash is
 // consistent with this definition. This is stable in the presence of internal mutability and
 // when the inner value is dropped.
-pub struct WeakKey<W> {
-    inner: W,
+pub struct ByPointer<P> {
+    inner: P,
 }
 
-impl<W: Weak> WeakKey<W> {
-    pub fn new(inner: W) -> Self {
+impl<P: Pointer> ByPointer<P> {
+    pub fn new(inner: P) -> Self {
         Self { inner }
     }
 
-    pub fn into_inner(self) -> W {
+    pub fn into_inner(self) -> P {
         self.inner
     }
 
-    pub fn inner(&self) -> &W {
+    pub fn inner(&self) -> &P {
         &self.inner
     }
 
-    pub fn upgrade(&self) -> Option<W::Strong> {
+    pub fn upgrade(&self) -> Option<P::Strong> {
         self.inner.upgrade()
     }
 }
 
-// Note that `WeakKey` must not implement `std::borrow::Borrow` because that requires equality and
-// comparison to agree with those of the borrowed type.
+// Note that `ByPointer` must not implement `std::borrow::Borrow` because that requires equality
+// and comparison to agree with those of the borrowed type.
 
-impl<W: Weak> Clone for WeakKey<W> {
+impl<P: Pointer> Clone for ByPointer<P> {
     fn clone(&self) -> Self {
         Self::new(self.inner.clone())
     }
 }
 
-impl<W: Weak> core::fmt::Debug for WeakKey<W> {
+impl<P: Pointer> core::fmt::Debug for ByPointer<P> {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
-        fmt.debug_tuple("WeakKey").field(&self.inner.key()).finish()
+        fmt.debug_tuple("ByPointer").field(&self.inner.key()).finish()
     }
 }
 
-impl<W: Weak> PartialEq for WeakKey<W> {
+impl<P: Pointer> PartialEq for ByPointer<P> {
     fn eq(&self, other: &Self) -> bool {
-        // This is identical to `Weak::ptr_eq` for both implementations but clarifies that it
-        // agrees with the implementations of `Hash` and `Ord`.
+        // This is identical to `Weak::ptr_eq`/`Rc::ptr_eq` for these implementations but clarifies
+        // that it agrees with the implementations of `Hash` and `Ord`.
         self.inner.key() == other.inner.key()
     }
 }
 
-impl<W: Weak> Eq for WeakKey<W> {}
+impl<P: Pointer> Eq for ByPointer<P> {}
 
-impl<W: Weak> core::hash::Hash for WeakKey<W> {
+impl<P: Pointer> core::hash::Hash for ByPointer<P> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.inner.key().hash(state)
     }
 }
 
-impl<W: Weak> PartialOrd for WeakKey<W> {
+impl<P: Pointer> PartialOrd for ByPointer<P> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<W: Weak> Ord for WeakKey<W> {
+impl<P: Pointer> Ord for ByPointer<P> {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.inner.key().cmp(&other.inner.key())
     }
 }
 
-impl<W: Weak> From<W> for WeakKey<W> {
-    fn from(value: W) -> Self {
+impl<P: Pointer> From<P> for ByPointer<P> {
+    fn from(value: P) -> Self {
         Self::new(value)
     }
 }
@@ -4,7 +4,7 @@
 
 extern crate alloc;
 
-#[cfg(doc)]
+#[cfg(any(feature = "std", doc))]
 extern crate std;
 
 mod generic;
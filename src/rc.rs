@@ -0,0 +1,17 @@
+mod strong;
+mod tree_map;
+mod weak;
+
+#[cfg(feature = "std")]
+mod hash_map;
+#[cfg(feature = "std")]
+mod hash_set;
+
+pub use strong::RcKey;
+pub use tree_map::WeakKeyTreeMap;
+pub use weak::WeakKey;
+
+#[cfg(feature = "std")]
+pub use hash_map::WeakKeyHashMap;
+#[cfg(feature = "std")]
+pub use hash_set::WeakKeySet;
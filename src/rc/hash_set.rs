@@ -0,0 +1,114 @@
+use super::WeakKeyHashMap;
+use alloc::rc::Rc;
+
+/// A [`HashSet`](std::collections::HashSet)-like collection of [`WeakKey`](super::WeakKey)s,
+/// automatically discarding entries once the allocation backing them is dropped.
+///
+/// This is a thin wrapper around [`WeakKeyHashMap<K, ()>`](WeakKeyHashMap) and inherits its
+/// amortized cleanup policy.
+pub struct WeakKeySet<K> {
+    map: WeakKeyHashMap<K, ()>,
+}
+
+impl<K> WeakKeySet<K> {
+    /// Returns a new, empty [`WeakKeySet`].
+    pub fn new() -> Self {
+        Self {
+            map: WeakKeyHashMap::new(),
+        }
+    }
+
+    /// Returns the number of live and not-yet-swept-dead entries in the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Inserts `key`, returning `true` if it was not already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::rc::WeakKeySet;
+    /// let rc = std::rc::Rc::new(());
+    /// let mut set = WeakKeySet::new();
+    /// assert!(set.insert(&rc));
+    /// assert!(!set.insert(&rc));
+    /// ```
+    pub fn insert(&mut self, key: &Rc<K>) -> bool {
+        self.map.insert(key, ()).is_none()
+    }
+
+    /// Returns `true` if `key` is present in the set.
+    pub fn contains(&self, key: &Rc<K>) -> bool {
+        self.map.get(key).is_some()
+    }
+
+    /// Removes `key`, returning `true` if it was present.
+    pub fn remove(&mut self, key: &Rc<K>) -> bool {
+        self.map.remove(key).is_some()
+    }
+
+    /// Retains only the entries for which `f` returns `true`.
+    ///
+    /// Entries whose key has already been dropped are discarded regardless of what `f` returns.
+    pub fn retain<F: FnMut(&K) -> bool>(&mut self, mut f: F) {
+        self.map.retain(|key, ()| f(key));
+    }
+
+    /// Drops all entries whose key has already been dropped.
+    pub fn cleanup(&mut self) {
+        self.map.cleanup();
+    }
+}
+
+impl<K> Default for WeakKeySet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestValue;
+
+    #[test]
+    fn insert_and_contains() {
+        let rc = Rc::new(TestValue);
+        let mut set = WeakKeySet::new();
+        assert!(set.insert(&rc));
+        assert!(!set.insert(&rc));
+        assert!(set.contains(&rc));
+    }
+
+    #[test]
+    fn remove() {
+        let rc = Rc::new(TestValue);
+        let mut set = WeakKeySet::new();
+        set.insert(&rc);
+        assert!(set.remove(&rc));
+        assert!(!set.remove(&rc));
+    }
+
+    #[test]
+    fn cleanup_drops_dead_entries() {
+        let mut set = WeakKeySet::new();
+        {
+            let rc = Rc::new(TestValue);
+            set.insert(&rc);
+        }
+        let live = Rc::new(TestValue);
+        set.insert(&live);
+        assert_eq!(set.len(), 2);
+        set.cleanup();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&live));
+    }
+}
@@ -1,7 +1,8 @@
+use super::weak::WeakKey;
 use crate::generic;
 use alloc::rc::{Rc};
 
-impl<T> generic::Pointer for Rc<T> {
+impl<T: ?Sized> generic::Pointer for Rc<T> {
     type Strong = Rc<T>;
     type Key = *const ();
 
@@ -10,6 +11,9 @@ impl<T> generic::Pointer for Rc<T> {
     }
 
     fn key(&self) -> Self::Key {
+        // Casting away the fat pointer's metadata discards the length/vtable, keeping only the
+        // data pointer that identifies the allocation. This is what every `Eq`/`Ord`/`Hash` impl
+        // here relies on, so it must agree for every clone of the same allocation.
         Rc::as_ptr(self) as *const ()
     }
 }
@@ -19,11 +23,11 @@ impl<T> generic::Pointer for Rc<T> {
 /// Equality and comparisons are implemented in terms of the inner value pointer and the hash is
 /// consistent with this definition. This is stable in the presence of internal mutability and
 /// when the inner value is dropped.
-pub struct RcKey<T> {
+pub struct RcKey<T: ?Sized> {
     inner: generic::ByPointer<Rc<T>>,
 }
 
-impl<T> RcKey<T> {
+impl<T: ?Sized> RcKey<T> {
     /// Returns a [`RcKey`] wrapping the provided [`Rc`].
     ///
     /// # Examples
@@ -65,9 +69,55 @@ impl<T> RcKey<T> {
     pub fn inner(&self) -> &Rc<T> {
         self.inner.inner()
     }
+
+    /// Returns the number of strong references to the allocation, including this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::rc::RcKey;
+    /// let rc = std::rc::Rc::new(());
+    /// let key = RcKey::new(rc.clone());
+    /// assert_eq!(key.strong_count(), 2);
+    /// ```
+    pub fn strong_count(&self) -> usize {
+        Rc::strong_count(self.inner())
+    }
+
+    /// Returns the number of weak references to the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::rc::RcKey;
+    /// let rc = std::rc::Rc::new(());
+    /// let key = RcKey::new(rc);
+    /// assert_eq!(key.weak_count(), 0);
+    /// ```
+    pub fn weak_count(&self) -> usize {
+        Rc::weak_count(self.inner())
+    }
+
+    /// Returns a [`WeakKey`] referring to the same allocation, without affecting the strong
+    /// count.
+    ///
+    /// This is equivalent to `WeakKey::from(&key)` but is provided for convenience, mirroring
+    /// [`std::rc::Rc::downgrade`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::rc::RcKey;
+    /// let rc = std::rc::Rc::new(());
+    /// let key = RcKey::new(rc);
+    /// assert!(key.downgrade().upgrade().is_some());
+    /// ```
+    pub fn downgrade(&self) -> WeakKey<T> {
+        WeakKey::from(self)
+    }
 }
 
-impl<T> Clone for RcKey<T> {
+impl<T: ?Sized> Clone for RcKey<T> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
@@ -75,45 +125,45 @@ impl<T> Clone for RcKey<T> {
     }
 }
 
-impl<T> PartialEq for RcKey<T> {
+impl<T: ?Sized> PartialEq for RcKey<T> {
     fn eq(&self, other: &Self) -> bool {
         self.inner.eq(&other.inner)
     }
 }
 
-impl<T> Eq for RcKey<T> {}
+impl<T: ?Sized> Eq for RcKey<T> {}
 
-impl<T> PartialOrd for RcKey<T> {
+impl<T: ?Sized> PartialOrd for RcKey<T> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        Some(self.inner.cmp(&other.inner))
+        Some(self.cmp(other))
     }
 }
 
-impl<T> Ord for RcKey<T> {
+impl<T: ?Sized> Ord for RcKey<T> {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.inner.cmp(&other.inner)
     }
 }
 
-impl<T> core::hash::Hash for RcKey<T> {
+impl<T: ?Sized> core::hash::Hash for RcKey<T> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.inner.hash(state)
     }
 }
 
-impl<T> From<Rc<T>> for RcKey<T> {
+impl<T: ?Sized> From<Rc<T>> for RcKey<T> {
     fn from(value: Rc<T>) -> Self {
         Self::new(value)
     }
 }
 
-impl<T> From<&Rc<T>> for RcKey<T> {
+impl<T: ?Sized> From<&Rc<T>> for RcKey<T> {
     fn from(value: &Rc<T>) -> Self {
         Self::new(value.clone())
     }
 }
 
-impl<T> core::fmt::Debug for RcKey<T> {
+impl<T: ?Sized> core::fmt::Debug for RcKey<T> {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.inner.fmt(fmt)
     }
@@ -208,5 +258,45 @@ mod tests {
             assert_eq!(ha == hb, Rc::ptr_eq(&wa, &wb));
         }
 
+        #[test]
+        fn strong_count(w in test_rc()) {
+            let key: RcKey<_> = w.clone().into();
+            assert_eq!(key.strong_count(), Rc::strong_count(&w));
+        }
+
+        #[test]
+        fn weak_count(w in test_rc()) {
+            let key: RcKey<_> = w.clone().into();
+            assert_eq!(key.weak_count(), Rc::weak_count(&w));
+            let weak = key.downgrade();
+            assert_eq!(key.weak_count(), Rc::weak_count(&w));
+            drop(weak);
+        }
+
+        #[test]
+        fn downgrade(w in test_rc()) {
+            let key: RcKey<_> = w.clone().into();
+            let weak = key.downgrade();
+            assert_eq!(weak.upgrade().map(|key| Rc::as_ptr(key.inner())), Some(Rc::as_ptr(&w)));
+        }
+
+    }
+
+    #[test]
+    fn unsized_slice() {
+        let a: Rc<[u8]> = Rc::from([1u8, 2, 3]);
+        let b = a.clone();
+        let c: Rc<[u8]> = Rc::from([1u8, 2, 3]);
+        assert_eq!(RcKey::from(&a), RcKey::from(&b));
+        assert_ne!(RcKey::from(&a), RcKey::from(&c));
+    }
+
+    #[test]
+    fn unsized_trait_object() {
+        let a: Rc<dyn core::fmt::Debug> = Rc::new(TestValue);
+        let b = a.clone();
+        let c: Rc<dyn core::fmt::Debug> = Rc::new(TestValue);
+        assert_eq!(RcKey::from(&a), RcKey::from(&b));
+        assert_ne!(RcKey::from(&a), RcKey::from(&c));
     }
 }
@@ -0,0 +1,165 @@
+use super::WeakKey;
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+
+/// Minimum number of insertions between amortized dead-entry sweeps.
+///
+/// Bounds the overhead of checking the dead fraction on every insert while still keeping dead
+/// entries from accumulating unboundedly between explicit [`WeakKeyTreeMap::cleanup`] calls.
+const SWEEP_INTERVAL: usize = 32;
+
+/// Fraction of entries that must be dead before an amortized sweep evicts them.
+const DEAD_FRACTION_THRESHOLD: f64 = 0.25;
+
+/// An ordered, [`BTreeMap`]-backed map keyed by [`WeakKey`], automatically discarding entries
+/// once the allocation backing their key is dropped.
+///
+/// Entries are ordered by the pointer-based [`Ord`] that [`WeakKey`] derives from
+/// [`generic::ByPointer`](crate::generic), not by the key's value. Dead entries are purged
+/// explicitly with [`cleanup`](Self::cleanup), implicitly by [`retain`](Self::retain), and
+/// opportunistically: every [`insert`](Self::insert) counts towards an amortized sweep that runs
+/// [`cleanup`](Self::cleanup) once the fraction of dead entries crosses a threshold.
+pub struct WeakKeyTreeMap<K, V> {
+    map: BTreeMap<WeakKey<K>, V>,
+    ops_since_sweep: usize,
+}
+
+impl<K, V> WeakKeyTreeMap<K, V> {
+    /// Returns a new, empty [`WeakKeyTreeMap`].
+    pub fn new() -> Self {
+        Self {
+            map: BTreeMap::new(),
+            ops_since_sweep: 0,
+        }
+    }
+
+    /// Returns the number of live and not-yet-swept-dead entries in the map.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Inserts a value for the given key, returning the previous value if `key` was already
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::rc::WeakKeyTreeMap;
+    /// let rc = std::rc::Rc::new(());
+    /// let mut map = WeakKeyTreeMap::new();
+    /// assert_eq!(map.insert(&rc, 1), None);
+    /// assert_eq!(map.insert(&rc, 2), Some(1));
+    /// ```
+    pub fn insert(&mut self, key: &Rc<K>, value: V) -> Option<V> {
+        let result = self.map.insert(WeakKey::from(key), value);
+        self.sweep_if_due();
+        result
+    }
+
+    /// Returns a reference to the value associated with `key`, if present.
+    pub fn get(&self, key: &Rc<K>) -> Option<&V> {
+        self.map.get(&WeakKey::from(key))
+    }
+
+    /// Removes and returns the value associated with `key`, if present.
+    pub fn remove(&mut self, key: &Rc<K>) -> Option<V> {
+        self.map.remove(&WeakKey::from(key))
+    }
+
+    /// Retains only the entries for which `f` returns `true`.
+    ///
+    /// Entries whose key has already been dropped are discarded regardless of what `f` returns.
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.map.retain(|key, value| {
+            key.upgrade()
+                .map(|key| f(key.inner(), value))
+                .unwrap_or(false)
+        });
+        self.ops_since_sweep = 0;
+    }
+
+    /// Drops all entries whose key has already been dropped.
+    pub fn cleanup(&mut self) {
+        self.map.retain(|key, _| key.upgrade().is_some());
+        self.ops_since_sweep = 0;
+    }
+
+    fn sweep_if_due(&mut self) {
+        self.ops_since_sweep += 1;
+        if self.ops_since_sweep < SWEEP_INTERVAL || self.map.is_empty() {
+            return;
+        }
+        let dead = self.map.keys().filter(|key| key.upgrade().is_none()).count();
+        if dead as f64 >= DEAD_FRACTION_THRESHOLD * self.map.len() as f64 {
+            self.cleanup();
+        } else {
+            self.ops_since_sweep = 0;
+        }
+    }
+}
+
+impl<K, V> Default for WeakKeyTreeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestValue;
+
+    #[test]
+    fn insert_and_get() {
+        let rc = Rc::new(TestValue);
+        let mut map = WeakKeyTreeMap::new();
+        assert_eq!(map.insert(&rc, 1), None);
+        assert_eq!(map.get(&rc), Some(&1));
+        assert_eq!(map.insert(&rc, 2), Some(1));
+        assert_eq!(map.get(&rc), Some(&2));
+    }
+
+    #[test]
+    fn remove() {
+        let rc = Rc::new(TestValue);
+        let mut map = WeakKeyTreeMap::new();
+        map.insert(&rc, 1);
+        assert_eq!(map.remove(&rc), Some(1));
+        assert_eq!(map.remove(&rc), None);
+    }
+
+    #[test]
+    fn cleanup_drops_dead_entries() {
+        let mut map = WeakKeyTreeMap::new();
+        {
+            let rc = Rc::new(TestValue);
+            map.insert(&rc, 1);
+        }
+        let live = Rc::new(TestValue);
+        map.insert(&live, 2);
+        assert_eq!(map.len(), 2);
+        map.cleanup();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&live), Some(&2));
+    }
+
+    #[test]
+    fn amortized_sweep_bounds_dead_entry_growth() {
+        let mut map = WeakKeyTreeMap::new();
+        let live = Rc::new(TestValue);
+        map.insert(&live, 0);
+        for i in 0..SWEEP_INTERVAL * 4 {
+            let rc = Rc::new(TestValue);
+            map.insert(&rc, i);
+        }
+        assert!(map.len() < SWEEP_INTERVAL * 4);
+        assert_eq!(map.get(&live), Some(&0));
+    }
+}
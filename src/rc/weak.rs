@@ -1,7 +1,8 @@
+use super::strong::RcKey;
 use crate::generic;
 use alloc::rc::{Rc, Weak};
 
-impl<T> generic::Pointer for Weak<T> {
+impl<T: ?Sized> generic::Pointer for Weak<T> {
     type Strong = Rc<T>;
     type Key = *const ();
 
@@ -10,6 +11,8 @@ impl<T> generic::Pointer for Weak<T> {
     }
 
     fn key(&self) -> Self::Key {
+        // `as_ptr` already returns a thin `*const T`; casting straight to `*const ()` discards
+        // any fat-pointer metadata without an intermediate (and clippy-flagged) same-type cast.
         self.as_ptr() as *const ()
     }
 }
@@ -19,11 +22,18 @@ impl<T> generic::Pointer for Weak<T> {
 /// Equality and comparisons are implemented in terms of the inner value pointer and the hash is
 /// consistent with this definition. This is stable in the presence of internal mutability and
 /// when the inner value is dropped.
-pub struct WeakKey<T> {
+///
+/// Because the key is derived purely from the allocation's address, a dangling [`Weak`] (one
+/// created by [`Weak::new`] or whose allocation has since been freed) has no way to distinguish
+/// itself from another dangling `Weak` that happens to reuse the same address after the original
+/// allocation is freed. Keys are therefore only guaranteed to be distinct while at least one of
+/// the two allocations they were derived from is still live; do not rely on inequality between two
+/// dead keys.
+pub struct WeakKey<T: ?Sized> {
     inner: generic::ByPointer<Weak<T>>,
 }
 
-impl<T> WeakKey<T> {
+impl<T: ?Sized> WeakKey<T> {
     /// Returns a [`WeakKey`] with the inner [`Weak`].
     ///
     /// # Examples
@@ -65,13 +75,11 @@ impl<T> WeakKey<T> {
         self.inner.inner()
     }
 
-    /// Attempts to upgrade the [`Weak`] pointer to an [`Rc`], delaying dropping of the inner value
-    /// if successful.
+    /// Attempts to upgrade the [`Weak`] pointer to an [`RcKey`], delaying dropping of the inner
+    /// value if successful.
     ///
     /// Returns [`None`] if the inner value has since been dropped.
     ///
-    /// This is equivalent to `self.inner().upgrade()` but is provided for convenience.
-    ///
     /// # Examples
     ///
     /// ```
@@ -85,12 +93,26 @@ impl<T> WeakKey<T> {
     /// let rc = std::rc::Rc::new(());
     /// assert!(WeakKey::new(std::rc::Rc::downgrade(&rc)).upgrade().is_some());
     /// ```
-    pub fn upgrade(&self) -> Option<Rc<T>> {
-        self.inner.upgrade()
+    pub fn upgrade(&self) -> Option<RcKey<T>> {
+        self.inner.upgrade().map(RcKey::new)
+    }
+
+    /// Returns the number of weak references to the allocation, including this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::rc::WeakKey;
+    /// let rc = std::rc::Rc::new(());
+    /// let key = WeakKey::from(&rc);
+    /// assert_eq!(key.weak_count(), 1);
+    /// ```
+    pub fn weak_count(&self) -> usize {
+        self.inner.inner().weak_count()
     }
 }
 
-impl<T> Clone for WeakKey<T> {
+impl<T: ?Sized> Clone for WeakKey<T> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
@@ -98,47 +120,57 @@ impl<T> Clone for WeakKey<T> {
     }
 }
 
-impl<T> PartialEq for WeakKey<T> {
+impl<T: ?Sized> PartialEq for WeakKey<T> {
     fn eq(&self, other: &Self) -> bool {
         self.inner.eq(&other.inner)
     }
 }
 
-impl<T> Eq for WeakKey<T> {}
+impl<T: ?Sized> Eq for WeakKey<T> {}
 
-impl<T> PartialOrd for WeakKey<T> {
+impl<T: ?Sized> PartialOrd for WeakKey<T> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        Some(self.inner.cmp(&other.inner))
+        Some(self.cmp(other))
     }
 }
 
-impl<T> Ord for WeakKey<T> {
+impl<T: ?Sized> Ord for WeakKey<T> {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.inner.cmp(&other.inner)
     }
 }
 
-impl<T> core::hash::Hash for WeakKey<T> {
+impl<T: ?Sized> core::hash::Hash for WeakKey<T> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.inner.hash(state)
     }
 }
 
-impl<T> From<Weak<T>> for WeakKey<T> {
+impl<T: ?Sized> From<Weak<T>> for WeakKey<T> {
     fn from(value: Weak<T>) -> Self {
         Self::new(value)
     }
 }
 
-impl<T> From<&Rc<T>> for WeakKey<T> {
+impl<T: ?Sized> From<&Rc<T>> for WeakKey<T> {
     fn from(value: &Rc<T>) -> Self {
         Rc::downgrade(value).into()
     }
 }
 
-impl<T> core::fmt::Debug for WeakKey<T> {
+impl<T: ?Sized> From<&RcKey<T>> for WeakKey<T> {
+    fn from(value: &RcKey<T>) -> Self {
+        Rc::downgrade(value.inner()).into()
+    }
+}
+
+impl<T: ?Sized> core::fmt::Debug for WeakKey<T> {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
-        self.inner.fmt(fmt)
+        let weak = self.inner.inner();
+        fmt.debug_struct("WeakKey")
+            .field("key", &weak.as_ptr())
+            .field("alive", &(weak.strong_count() > 0))
+            .finish()
     }
 }
 
@@ -200,7 +232,10 @@ mod tests {
         #[test]
         fn upgrade((weak, strong) in test_rc()) {
             let key: WeakKey<_> = weak.clone().into();
-            assert_eq!(key.upgrade().as_ref().map(Rc::as_ptr), strong.as_ref().map(Rc::as_ptr));
+            assert_eq!(
+                key.upgrade().map(|key| Rc::as_ptr(key.inner())),
+                strong.as_ref().map(Rc::as_ptr),
+            );
         }
 
         #[test]
@@ -217,6 +252,15 @@ mod tests {
             }
         }
 
+        #[test]
+        fn from_rc_key((_, strong) in test_rc()) {
+            if let Some(strong) = strong {
+                let rc_key = RcKey::new(strong.clone());
+                let key = WeakKey::from(&rc_key);
+                assert_eq!(key.into_inner().as_ptr(), Rc::as_ptr(&strong));
+            }
+        }
+
         #[test]
         fn clone((weak, _) in test_rc()) {
             let key: WeakKey<_> = weak.clone().into();
@@ -258,5 +302,41 @@ mod tests {
             assert_eq!(ha == hb, wa.ptr_eq(&wb));
         }
 
+        #[test]
+        fn stable_across_drop(wa in test_rc(), wb in test_rc()) {
+            let rc = Rc::new(TestValue);
+            let ka: WeakKey<_> = Rc::downgrade(&rc).into();
+            let (wa, _) = wa;
+            let (wb, _) = wb;
+            let kb_before: WeakKey<_> = wa.clone().into();
+            let kc_before: WeakKey<_> = wb.clone().into();
+            let eq_ab_before = ka == kb_before;
+            let eq_ac_before = ka == kc_before;
+            let lt_ab_before = ka < kb_before;
+            let lt_ac_before = ka < kc_before;
+            let mut ha_before = TestHasher::default();
+            ka.hash(&mut ha_before);
+
+            drop(rc);
+
+            let kb_after: WeakKey<_> = wa.clone().into();
+            let kc_after: WeakKey<_> = wb.clone().into();
+            assert_eq!(eq_ab_before, ka == kb_after);
+            assert_eq!(eq_ac_before, ka == kc_after);
+            assert_eq!(lt_ab_before, ka < kb_after);
+            assert_eq!(lt_ac_before, ka < kc_after);
+
+            let mut ha_after = TestHasher::default();
+            ka.hash(&mut ha_after);
+            assert_eq!(ha_before, ha_after);
+        }
+
+        #[test]
+        fn weak_count((weak, strong) in test_rc()) {
+            let key: WeakKey<_> = weak.clone().into();
+            assert_eq!(key.weak_count(), weak.weak_count());
+            drop(strong);
+        }
+
     }
 }
@@ -0,0 +1,5 @@
+mod arc;
+mod weak;
+
+pub use arc::ArcKey;
+pub use weak::WeakKey;
@@ -1,3 +1,4 @@
+use super::weak::WeakKey;
 use crate::generic;
 use alloc::sync::Arc;
 
@@ -65,6 +66,52 @@ impl<T> ArcKey<T> {
     pub fn inner(&self) -> &Arc<T> {
         self.inner.inner()
     }
+
+    /// Returns the number of strong references to the allocation, including this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::sync::ArcKey;
+    /// let arc = std::sync::Arc::new(());
+    /// let key = ArcKey::new(arc.clone());
+    /// assert_eq!(key.strong_count(), 2);
+    /// ```
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(self.inner())
+    }
+
+    /// Returns the number of weak references to the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::sync::ArcKey;
+    /// let arc = std::sync::Arc::new(());
+    /// let key = ArcKey::new(arc);
+    /// assert_eq!(key.weak_count(), 0);
+    /// ```
+    pub fn weak_count(&self) -> usize {
+        Arc::weak_count(self.inner())
+    }
+
+    /// Returns a [`WeakKey`] referring to the same allocation, without affecting the strong
+    /// count.
+    ///
+    /// This is equivalent to `WeakKey::from(&key)` but is provided for convenience, mirroring
+    /// [`std::sync::Arc::downgrade`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::sync::ArcKey;
+    /// let arc = std::sync::Arc::new(());
+    /// let key = ArcKey::new(arc);
+    /// assert!(key.downgrade().upgrade().is_some());
+    /// ```
+    pub fn downgrade(&self) -> WeakKey<T> {
+        WeakKey::from(self)
+    }
 }
 
 impl<T> Clone for ArcKey<T> {
@@ -85,7 +132,7 @@ impl<T> Eq for ArcKey<T> {}
 
 impl<T> PartialOrd for ArcKey<T> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        Some(self.inner.cmp(&other.inner))
+        Some(self.cmp(other))
     }
 }
 
@@ -208,5 +255,27 @@ mod tests {
             assert_eq!(ha == hb, Arc::ptr_eq(&wa, &wb));
         }
 
+        #[test]
+        fn strong_count(w in test_arc()) {
+            let key: ArcKey<_> = w.clone().into();
+            assert_eq!(key.strong_count(), Arc::strong_count(&w));
+        }
+
+        #[test]
+        fn weak_count(w in test_arc()) {
+            let key: ArcKey<_> = w.clone().into();
+            assert_eq!(key.weak_count(), Arc::weak_count(&w));
+            let weak = key.downgrade();
+            assert_eq!(key.weak_count(), Arc::weak_count(&w));
+            drop(weak);
+        }
+
+        #[test]
+        fn downgrade(w in test_arc()) {
+            let key: ArcKey<_> = w.clone().into();
+            let weak = key.downgrade();
+            assert_eq!(weak.upgrade().map(|key| Arc::as_ptr(key.inner())), Some(Arc::as_ptr(&w)));
+        }
+
     }
 }
@@ -1,7 +1,8 @@
+use super::arc::ArcKey;
 use crate::generic;
 use alloc::sync::{Arc, Weak};
 
-impl<T> generic::Weak for Weak<T> {
+impl<T> generic::Pointer for Weak<T> {
     type Strong = Arc<T>;
     type Key = *const ();
 
@@ -19,8 +20,15 @@ impl<T> generic::Weak for Weak<T> {
 /// Equality and comparisons are implemented in terms of the inner value pointer and the hash is
 /// consistent with this definition. This is stable in the presence of internal mutability and
 /// when the inner value is dropped.
+///
+/// Because the key is derived purely from the allocation's address, a dangling [`Weak`] (one
+/// created by [`Weak::new`] or whose allocation has since been freed) has no way to distinguish
+/// itself from another dangling `Weak` that happens to reuse the same address after the original
+/// allocation is freed. Keys are therefore only guaranteed to be distinct while at least one of
+/// the two allocations they were derived from is still live; do not rely on inequality between two
+/// dead keys.
 pub struct WeakKey<T> {
-    inner: generic::WeakKey<Weak<T>>,
+    inner: generic::ByPointer<Weak<T>>,
 }
 
 impl<T> WeakKey<T> {
@@ -29,13 +37,13 @@ impl<T> WeakKey<T> {
     /// # Examples
     ///
     /// ```
-    /// # use weakkey::arc::WeakKey;
+    /// # use weakkey::sync::WeakKey;
     /// let weak = std::sync::Weak::<()>::new();
     /// assert_eq!(WeakKey::new(weak.clone()), WeakKey::new(weak));
     /// ```
     pub fn new(inner: Weak<T>) -> Self {
         Self {
-            inner: generic::WeakKey::new(inner),
+            inner: generic::ByPointer::new(inner),
         }
     }
 
@@ -44,7 +52,7 @@ impl<T> WeakKey<T> {
     /// # Examples
     ///
     /// ```
-    /// # use weakkey::arc::WeakKey;
+    /// # use weakkey::sync::WeakKey;
     /// let weak = std::sync::Weak::<()>::new();
     /// assert!(WeakKey::new(weak.clone()).into_inner().ptr_eq(&weak));
     /// ```
@@ -57,7 +65,7 @@ impl<T> WeakKey<T> {
     /// # Examples
     ///
     /// ```
-    /// # use weakkey::arc::WeakKey;
+    /// # use weakkey::sync::WeakKey;
     /// let weak = std::sync::Weak::<()>::new();
     /// assert!(WeakKey::new(weak.clone()).inner().ptr_eq(&weak));
     /// ```
@@ -65,28 +73,40 @@ impl<T> WeakKey<T> {
         self.inner.inner()
     }
 
-    /// Attempts to upgrade the [`Weak`] pointer to an [`Arc`], delaying dropping of the inner value
-    /// if successful.
+    /// Attempts to upgrade the [`Weak`] pointer to an [`ArcKey`], delaying dropping of the inner
+    /// value if successful.
     ///
     /// Returns [`None`] if the inner value has since been dropped.
     ///
-    /// This is equivalent to `self.inner().upgrade()` but is provided for convenience.
-    ///
     /// # Examples
     ///
     /// ```
-    /// # use weakkey::arc::WeakKey;
+    /// # use weakkey::sync::WeakKey;
     /// let weak = std::sync::Weak::<()>::new();
     /// assert!(WeakKey::new(weak).upgrade().is_none());
     /// ```
     ///
     /// ```
-    /// # use weakkey::arc::WeakKey;
+    /// # use weakkey::sync::WeakKey;
     /// let arc = std::sync::Arc::new(());
     /// assert!(WeakKey::new(std::sync::Arc::downgrade(&arc)).upgrade().is_some());
     /// ```
-    pub fn upgrade(&self) -> Option<Arc<T>> {
-        self.inner.upgrade()
+    pub fn upgrade(&self) -> Option<ArcKey<T>> {
+        self.inner.upgrade().map(ArcKey::new)
+    }
+
+    /// Returns the number of weak references to the allocation, including this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use weakkey::sync::WeakKey;
+    /// let arc = std::sync::Arc::new(());
+    /// let key = WeakKey::from(&arc);
+    /// assert_eq!(key.weak_count(), 1);
+    /// ```
+    pub fn weak_count(&self) -> usize {
+        self.inner.inner().weak_count()
     }
 }
 
@@ -108,7 +128,7 @@ impl<T> Eq for WeakKey<T> {}
 
 impl<T> PartialOrd for WeakKey<T> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        Some(self.inner.cmp(&other.inner))
+        Some(self.cmp(other))
     }
 }
 
@@ -136,9 +156,19 @@ impl<T> From<&Arc<T>> for WeakKey<T> {
     }
 }
 
+impl<T> From<&ArcKey<T>> for WeakKey<T> {
+    fn from(value: &ArcKey<T>) -> Self {
+        Arc::downgrade(value.inner()).into()
+    }
+}
+
 impl<T> core::fmt::Debug for WeakKey<T> {
     fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
-        self.inner.fmt(fmt)
+        let weak = self.inner.inner();
+        fmt.debug_struct("WeakKey")
+            .field("key", &weak.as_ptr())
+            .field("alive", &(weak.strong_count() > 0))
+            .finish()
     }
 }
 
@@ -157,13 +187,13 @@ mod tests {
             Just((Weak::new(), None)),
             // Dangling case.
             {
-                let rc = Arc::new(TestValue);
-                Just((Arc::downgrade(&rc), None))
+                let arc = Arc::new(TestValue);
+                Just((Arc::downgrade(&arc), None))
             },
             // Valid case.
             {
-                let rc = Arc::new(TestValue);
-                Just((Arc::downgrade(&rc), Some(rc)))
+                let arc = Arc::new(TestValue);
+                Just((Arc::downgrade(&arc), Some(arc)))
             },
         ]
     }
@@ -200,7 +230,10 @@ mod tests {
         #[test]
         fn upgrade((weak, strong) in test_arc()) {
             let key: WeakKey<_> = weak.clone().into();
-            assert_eq!(key.upgrade().as_ref().map(Arc::as_ptr), strong.as_ref().map(Arc::as_ptr));
+            assert_eq!(
+                key.upgrade().map(|key| Arc::as_ptr(key.inner())),
+                strong.as_ref().map(Arc::as_ptr),
+            );
         }
 
         #[test]
@@ -217,6 +250,15 @@ mod tests {
             }
         }
 
+        #[test]
+        fn from_arc_key((_, strong) in test_arc()) {
+            if let Some(strong) = strong {
+                let arc_key = ArcKey::new(strong.clone());
+                let key = WeakKey::from(&arc_key);
+                assert_eq!(key.into_inner().as_ptr(), Arc::as_ptr(&strong));
+            }
+        }
+
         #[test]
         fn clone((weak, _) in test_arc()) {
             let key: WeakKey<_> = weak.clone().into();
@@ -258,5 +300,12 @@ mod tests {
             assert_eq!(ha == hb, wa.ptr_eq(&wb));
         }
 
+        #[test]
+        fn weak_count((weak, strong) in test_arc()) {
+            let key: WeakKey<_> = weak.clone().into();
+            assert_eq!(key.weak_count(), weak.weak_count());
+            drop(strong);
+        }
+
     }
 }